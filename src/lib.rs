@@ -28,6 +28,8 @@
 //! | `=>+`      | Clone    | Clones the current value                                       |
 //! | `=>#`      | Apply    | Performs a side effect (e.g. println!), returns original value |
 //! | `=>$`      | ApplyMut | Like Apply, but passes mutable reference                       |
+//! | `=>%`      | Match    | Fork on the value with `match`-style arms                      |
+//! | `=>~`      | Await    | Await the value as a future, then keep piping                  |
 //!
 //! #### Remember hints
 //!
@@ -37,6 +39,8 @@
 //! - `*` -- dereference → unwrap
 //! - `+` -- clone
 //! - `#` -- “hashtag debug” → apply
+//! - `%` -- “modulo/branch” → match on the value
+//! - `~` -- “wavy/async” → await the value
 //!
 //! If you have better association ideas, you are welcome to open a pull request!
 //!
@@ -170,7 +174,114 @@
 //!
 //! assert_eq!(wrapped, "[CORE]");
 //! ```
-//! 
+//!
+//! ### Branch with match arms
+//!
+//! `=>%` forks on the current value with `match`-style arms, producing a single
+//! value that flows onward.
+//!
+//! ```
+//! use spipe::spipe;
+//!
+//! let doubled = spipe!(Some(5) =>% { Some(x) => x * 2, None => 0 });
+//! assert_eq!(doubled, 10);
+//! ```
+//!
+//! ### Point-free composition
+//!
+//! A bare `_` in the initial position compiles the pipeline into a reusable
+//! closure instead of a single value, so it can be stored and applied later.
+//!
+//! ```
+//! use spipe::spipe;
+//!
+//! fn double(n: i32) -> i32 {
+//!     n * 2
+//! }
+//!
+//! let f = spipe!(_ => double => double);
+//! assert_eq!(f(3), 12);
+//!
+//! let doubled: Vec<i32> = [1, 2, 3]
+//!     .into_iter()
+//!     .map(spipe!(_ => double))
+//!     .collect();
+//! assert_eq!(doubled, vec![2, 4, 6]);
+//! ```
+//!
+//! ### Await inside async pipelines
+//!
+//! `=>~` awaits the current value as a future, then keeps piping. It works
+//! standalone — `fut =>~ =>@ f` awaits and then maps on a later stage — or
+//! combined with a following operation in the same stage — `fut =>~ f` awaits
+//! and then applies `f`.
+//!
+//! ```no_run
+//! use spipe::spipe;
+//!
+//! async fn fetch() -> i32 {
+//!     21
+//! }
+//!
+//! async fn fetch_opt() -> Option<i32> {
+//!     Some(21)
+//! }
+//!
+//! fn double(n: i32) -> i32 {
+//!     n * 2
+//! }
+//!
+//! async fn run() {
+//!     // combined: await, then apply `double` in the same stage
+//!     let combined = spipe!(fetch() =>~ double);
+//!     assert_eq!(combined, 42);
+//!
+//!     // standalone: await, then map over the Option on a separate stage
+//!     let mapped = spipe!(fetch_opt() =>~ =>@ double);
+//!     assert_eq!(mapped, Some(42));
+//! }
+//! ```
+//!
+//! ### Debug expansion
+//!
+//! [`spipe_dbg!`] expands to the same result as [`spipe!`], but lowers every
+//! stage to its own named binding so type errors point at the precise stage:
+//!
+//! ```
+//! use spipe::spipe_dbg;
+//!
+//! fn double(n: i32) -> i32 {
+//!     n * 2
+//! }
+//!
+//! let res = spipe_dbg!(
+//!     21
+//!         => double
+//!         => |x| x + 1
+//! );
+//! assert_eq!(res, 43);
+//!
+//! // Expands to roughly:
+//! // {
+//! //     let __stage_0 = double(21);
+//! //     let __stage_1 = (|x| x + 1)(__stage_0);
+//! //     __stage_1
+//! // }
+//! ```
+//!
+//! ### Stage-attributed errors
+//!
+//! A semantic failure in a stage is re-anchored onto that stage rather than the
+//! whole invocation. A method call whose method path is not a bare identifier
+//! is rejected at the offending stage:
+//!
+//! ```compile_fail
+//! use spipe::spipe;
+//!
+//! // `.foo::bar()` is not a bare-ident method call, so this stage is rejected.
+//! let _ = spipe!(5 => .foo::bar());
+//! ```
+//!
 //! `spipe!` helps you write cleaner, more expressive Rust pipelines by choosing
 //! the right transformation based on your intent:
 //! - Use `=>@`, `=>&`, `=>?` for functional types (Result, Option)
@@ -196,3 +307,20 @@ pub fn spipe(input: TokenStream) -> TokenStream {
         Err(err) => err.to_compile_error().into(),
     }
 }
+
+/// Debug-expansion variant of [`spipe!`].
+///
+/// Lowers the pipeline into a block where each stage binds a uniquely named
+/// local (`__stage_0`, `__stage_1`, …) instead of producing one nested
+/// expression. Each binding carries the span of its originating stage, so type
+/// errors are attributed to the precise stage and intermediate types surface
+/// readably. The accepted syntax is identical to [`spipe!`].
+#[proc_macro]
+pub fn spipe_dbg(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as MacroInput).with_debug();
+
+    match input.run() {
+        Ok(expr) => quote::quote! { #expr }.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 
+use proc_macro2::Span;
 use syn::punctuated::Punctuated;
 use syn::parse::*;
 use syn::*;
@@ -13,6 +14,36 @@ macro_rules! pipe_const {
     (unwrap) => { Token![*] };
     (apply) => { Token![#] };
     (apply_mut) => { Token![$] };
+    (branch) => { Token![%] };
+    (await) => { Token![~] };
+}
+
+/// Catalogue of spipe's smart-pipe operators paired with a one-line meaning.
+///
+/// Used to build labeled diagnostics: when an unrecognized operator follows
+/// `=>`, the reporter lists every valid operator so the user does not have to
+/// guess what spipe understands.
+const PIPE_OPERATORS: &[(&str, &str)] = &[
+    ("=>&", "and_then — chain a fallible step on Result/Option"),
+    ("=>@", "map — transform the value inside Result/Option"),
+    ("=>?", "try — propagate errors/None with the `?` operator"),
+    ("=>*", "unwrap — call `.unwrap()` on the value"),
+    ("=>+", "clone — clone the current value before piping"),
+    ("=>#", "apply — run a side effect, keep the original value"),
+    ("=>$", "apply_mut — side effect on a mutable reference"),
+    ("=>%", "match — fork on the value with match-style arms"),
+    ("=>~", "await — await the value as a future, then keep piping"),
+];
+
+/// Build an error that spans exactly the offending operator token and spells
+/// out every smart-pipe operator spipe accepts, followed by a `help:` note.
+fn unknown_operator_error(span: Span) -> Error {
+    let mut msg = String::from("unknown smart-pipe operator; expected one of:\n");
+    for (op, meaning) in PIPE_OPERATORS {
+        msg.push_str(&format!("    {op:<4}{meaning}\n"));
+    }
+    msg.push_str("help: use a bare `=>` to pass the value straight to the next stage");
+    Error::new(span, msg)
 }
 
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq, Ord, Eq, Hash)]
@@ -33,6 +64,10 @@ pub enum PipeType {
     Apply,
     // =>$
     ApplyMut,
+    // =>%
+    Match,
+    // =>~
+    Await,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -44,6 +79,7 @@ pub enum PipeOp {
     TypeFrom(ExprPath),
     TypeTryFrom(ExprPath),
     TypeAs(Type),
+    Match(Vec<Arm>),
 }
 
 impl Parse for PipeOp {
@@ -108,10 +144,15 @@ impl Parse for PipeOp {
 struct PipeOpPair {
     pipe_type: PipeType,
     operation: PipeOp,
+    /// Span of the first token after `=>` (the operator byte, or for a bare
+    /// `=>` the start of the operation), so semantic failures in
+    /// [`MacroInput::run`] can point at the precise pipeline stage.
+    span:      Span,
 }
 
 impl Parse for PipeOpPair {
     fn parse(input: ParseStream) -> Result<Self> {
+        let op_span = input.span();
         let lookahead = input.lookahead1();
 
         let ty = if lookahead.peek(pipe_const!(and_then)) {
@@ -142,14 +183,69 @@ impl Parse for PipeOpPair {
             input.parse::<pipe_const!(apply_mut)>()?;
             PipeType::ApplyMut
         }
-        else {
+        else if lookahead.peek(pipe_const!(branch)) {
+            input.parse::<pipe_const!(branch)>()?;
+            PipeType::Match
+        }
+        else if lookahead.peek(pipe_const!(await)) {
+            input.parse::<pipe_const!(await)>()?;
+            PipeType::Await
+        }
+        // A bare `=>` is valid only when what follows actually starts a pipe
+        // operation; anything else is a stray operator byte we can name.
+        else if lookahead.peek(Token![.])
+            || lookahead.peek(syn::token::Paren)
+            || lookahead.peek(syn::Ident)
+            || lookahead.peek(Token![|])
+        {
             PipeType::Basic
+        }
+        else {
+            let mut err = unknown_operator_error(op_span);
+            // Surface any further problem in the same stage in one pass instead
+            // of forcing the user to fix errors one at a time.
+            // Step past the unrecognized byte so the operation after it is
+            // checked on its own.
+            let _ = input.parse::<proc_macro2::TokenTree>();
+            match input.parse::<PipeOp>() {
+                Err(op_err) => err.combine(op_err),
+                Ok(PipeOp::MethodCall(call)) => {
+                    if let Err(call_err) = try_get_call_ident(&call) {
+                        err.combine(call_err);
+                    }
+                },
+                Ok(_) => {},
+            }
+            return Err(err);
         };
 
-        let op: PipeOp = input.parse()?;
+        // The branch operator takes a brace block of `match` arms rather than a
+        // regular pipe operation, so parse it with syn's own arm parser.
+        let op = if ty == PipeType::Match {
+            let arms;
+            braced!(arms in input);
+            let mut parsed = Vec::new();
+            while !arms.is_empty() {
+                parsed.push(arms.call(Arm::parse)?);
+                if arms.peek(Token![,]) {
+                    arms.parse::<Token![,]>()?;
+                }
+            }
+            PipeOp::Match(parsed)
+        }
+        // `=>~` may stand alone (`fetch(url) =>~ =>@ parse`) or compose with a
+        // following operation in the same stage (`fetch(url) =>~ parse`), so a
+        // bare await with nothing to apply folds to a no-op.
+        else if ty == PipeType::Await && (input.is_empty() || input.peek(Token![=>])) {
+            PipeOp::NoOp
+        }
+        else {
+            input.parse()?
+        };
         Ok(PipeOpPair {
             pipe_type: ty,
-            operation: op
+            operation: op,
+            span:      op_span,
         })
     }
 }
@@ -157,25 +253,159 @@ impl Parse for PipeOpPair {
 pub struct MacroInput {
     initial:    Expr,
     pipe_pairs: VecDeque<PipeOpPair>,
+    /// Set when the initial position is a bare `_` placeholder, turning the
+    /// whole pipeline into a reusable closure instead of a computed value.
+    point_free: bool,
+    /// Set by the `spipe_dbg!` entry point to lower the pipeline into a block
+    /// of per-stage `let` bindings instead of one nested expression.
+    debug:      bool,
+}
+
+/// Identifier bound by the synthesized closure in point-free mode and used as
+/// the seed expression the pipeline folds over.
+const POINT_FREE_INPUT: &str = "__input";
+
+/// Re-anchor a semantic failure (e.g. a non-ident method call) onto the
+/// offending stage's leading token rather than the whole macro invocation.
+fn stage_error(stage_span: Span, err: Error) -> Error {
+    let mut staged = Error::new(
+        stage_span, format!("invalid pipeline stage: {err}")
+    );
+    staged.combine(err);
+    staged
 }
 
 impl MacroInput {
-    pub fn run(mut self) -> Result<Expr> {
-        let mut res = self.initial;
+    /// Enable debug-expansion mode, lowering every stage to a named `let`.
+    pub fn with_debug(mut self) -> Self {
+        self.debug = true;
+        self
+    }
+
+    pub fn run(self) -> Result<Expr> {
+        let MacroInput { initial, mut pipe_pairs, point_free, debug } = self;
+        let seed = if point_free {
+            path_to_expr(Path::from(create_ident(POINT_FREE_INPUT)))
+        }
+        else {
+            initial
+        };
+
         let mut closure_count: usize = 0;
-        while let Some(op) = self.pipe_pairs.pop_front() {
-            let pipe_applied_fn = apply_pipe(
-                op.pipe_type, res, &mut closure_count
-            );
-            res = pipe_applied_fn(op.operation)?
+        let body = if debug {
+            lower_debug(&mut pipe_pairs, seed, &mut closure_count)?
         }
-        Ok(res)
+        else {
+            fold_stages(&mut pipe_pairs, seed, &mut closure_count)?
+        };
+
+        // In point-free mode wrap the expansion in a closure binding the
+        // synthetic input identifier, yielding a reusable combinator.
+        Ok(if point_free { wrap_point_free(body) } else { body })
+    }
+}
+
+/// Fold the stages into a single, deeply nested expression (the default terse
+/// output).
+fn fold_stages(
+    pairs: &mut VecDeque<PipeOpPair>,
+    seed: Expr,
+    closure_count: &mut usize,
+) -> Result<Expr> {
+    let mut res = seed;
+    while let Some(op) = pairs.pop_front() {
+        let stage_span = op.span;
+        let pipe_applied_fn = apply_pipe(op.pipe_type, res, closure_count);
+        res = pipe_applied_fn(op.operation)
+            .map_err(|err| stage_error(stage_span, err))?;
+    }
+    Ok(res)
+}
+
+/// Lower the stages into a block where every stage binds a uniquely named
+/// local (`__stage_0`, `__stage_1`, …), each carrying the span of its
+/// originating stage so type errors point at the precise stage and
+/// intermediate types are inspectable.
+fn lower_debug(
+    pairs: &mut VecDeque<PipeOpPair>,
+    seed: Expr,
+    closure_count: &mut usize,
+) -> Result<Expr> {
+    let mut res = seed;
+    let mut stmts: Vec<Stmt> = Vec::new();
+    for idx in 0.. {
+        let Some(op) = pairs.pop_front() else { break };
+        let stage_span = op.span;
+        let pipe_applied_fn = apply_pipe(op.pipe_type, res, closure_count);
+        let applied = pipe_applied_fn(op.operation)
+            .map_err(|err| stage_error(stage_span, err))?;
+
+        let stage_ident = Ident::new(&format!("__stage_{idx}"), stage_span);
+        let expr_let = Local {
+            attrs:      vec![],
+            let_token:  Default::default(),
+            pat:        PatIdent {
+                attrs:      vec![],
+                by_ref:     None,
+                mutability: None,
+                ident:      stage_ident.clone(),
+                subpat:     None,
+            }
+            .into(),
+            init:       Some(LocalInit {
+                eq_token: Default::default(),
+                expr:     Box::new(applied),
+                diverge:  None,
+            }),
+            semi_token: Default::default(),
+        };
+        stmts.push(Stmt::Local(expr_let));
+        res = path_to_expr(Path::from(stage_ident));
+    }
+    // Tail expression yields the last stage's binding (or the bare seed).
+    stmts.push(Stmt::Expr(res, None));
+
+    Ok(ExprBlock {
+        attrs: vec![],
+        label: None,
+        block: Block {
+            brace_token: Default::default(),
+            stmts,
+        },
+    }
+    .into())
+}
+
+/// Wrap an expansion in a closure binding the point-free input identifier.
+fn wrap_point_free(body: Expr) -> Expr {
+    let input_pat = Pat::Ident(PatIdent {
+        attrs:      vec![],
+        by_ref:     None,
+        mutability: None,
+        ident:      create_ident(POINT_FREE_INPUT),
+        subpat:     None,
+    });
+    ExprClosure {
+        attrs:      vec![],
+        lifetimes:  None,
+        constness:  None,
+        movability: None,
+        asyncness:  None,
+        capture:    None,
+        or1_token:  Default::default(),
+        inputs:     Punctuated::from_iter([input_pat]),
+        or2_token:  Default::default(),
+        output:     ReturnType::Default,
+        body:       Box::new(body),
     }
+    .into()
 }
 
 impl Parse for MacroInput {
     fn parse(input: ParseStream) -> Result<Self> {
         let initial: Expr = input.parse()?;
+        // A bare `_` in the initial position switches on point-free mode.
+        let point_free = matches!(initial, Expr::Infer(_));
 
         let parsed = if input.peek(Token![=>]) {
             input.parse::<Token![=>]>()?;
@@ -189,6 +419,8 @@ impl Parse for MacroInput {
         Ok(Self {
             initial,
             pipe_pairs: parsed.into_iter().collect(),
+            point_free,
+            debug: false,
         })
     }
 }
@@ -251,6 +251,15 @@ pub fn apply_op(pipe: PipeOp, expr: Expr) -> Result<Expr> {
             };
             Ok(Expr::Cast(as_call))
         },
+        Match(arms) => {
+            Ok(Expr::Match(ExprMatch {
+                attrs:       vec![],
+                match_token: Default::default(),
+                expr:        Box::new(expr),
+                brace_token: Default::default(),
+                arms,
+            }))
+        },
     }
 }
 
@@ -293,6 +302,18 @@ pub fn apply_pipe(pipe: PipeType, expr: Expr, closure_count: &mut usize) -> Box<
         ApplyMut => {
             *closure_count += 1;
             Box::new(|x| get_apply_block(x, expr, true, closure_count))
-        }
+        },
+        Match => {
+            Box::new(|x| apply_op(x, expr))
+        },
+        Await => {
+            let await_expr = ExprAwait {
+                attrs:       vec![],
+                base:        Box::new(expr),
+                dot_token:   Default::default(),
+                await_token: Default::default(),
+            };
+            Box::new(move |x| apply_op(x, await_expr.into()))
+        },
     }
 }
\ No newline at end of file